@@ -1,4 +1,16 @@
-use bevy::{prelude::*, render::view::RenderLayers, utils::HashMap};
+use std::any::TypeId;
+
+use bevy::{
+    prelude::*,
+    render::{
+        camera::RenderTarget,
+        render_resource::{
+            Extent3d, TextureDescriptor, TextureDimension, TextureFormat, TextureUsages,
+        },
+        view::RenderLayers,
+    },
+    utils::{HashMap, HashSet},
+};
 use bevy_asset_loader::{
     asset_collection::AssetCollection,
     dynamic_asset::{DynamicAsset, DynamicAssetCollection},
@@ -37,14 +49,503 @@ impl Plugin for MeshlessVisualizerPlugin {
         .add_plugins(RonAssetPlugin::<EditorIconAssetCollection>::new(&[
             "icons.ron",
         ]))
+        .init_resource::<ObjectPreviewCache>()
+        .init_resource::<ObjectPreviewSettings>()
+        .init_resource::<MeshlessIconRegistry>()
+        // the four builtin icon systems are scheduled directly below, so seed
+        // the dedup set with their types to keep register_meshless_icon from
+        // scheduling a second identical system for any of them
+        .insert_resource(RegisteredIconSystems(HashSet::from_iter([
+            TypeId::of::<DirectionalLight>(),
+            TypeId::of::<SpotLight>(),
+            TypeId::of::<PointLight>(),
+            TypeId::of::<Camera>(),
+        ])))
+        .init_resource::<ClickableSphereMeshes>()
+        .init_resource::<IconThemeDirty>()
+        .init_resource::<IconDisplaySettings>()
+        .register_type::<IconDisplaySettings>()
+        .register_type::<IconSizeMode>()
+        .add_event::<SwitchIconThemeEvent>()
+        .add_systems(
+            Update,
+            (
+                visualize_registered_icon::<DirectionalLight>,
+                visualize_registered_icon::<SpotLight>,
+                visualize_registered_icon::<PointLight>,
+                visualize_registered_icon::<Camera>,
+                visualize_custom_meshless,
+                refresh_changed_object_impostors,
+                // finalize must observe a rendered frame before tearing the
+                // preview camera/model down, so order it strictly after request
+                (request_object_previews, finalize_object_previews).chain(),
+                swap_object_impostors,
+                scale_distant_icons,
+                switch_icon_theme,
+                watch_icon_theme,
+            )
+                .in_set(EditorSet::Editor),
+        )
+        // strip runs in PreUpdate so its deferred despawns flush at the end of
+        // that schedule, before the Update-time serialization reads the same
+        // EditorEvent::Save — so the exported scene never sees the gizmos
+        .add_systems(
+            PreUpdate,
+            strip_editor_icons.run_if(in_state(EditorState::Editor)),
+        )
         .add_systems(
             Update,
-            (visualize_meshless, visualize_custom_meshless).in_set(EditorSet::Editor),
+            rebuild_icon_assets.in_set(EditorSet::Editor),
+        )
+        .add_systems(
+            OnEnter(EditorState::Editor),
+            (setup_icon_theme, register_builtin_meshless_icons).chain(),
         )
         .editor_registry::<CustomMeshless>();
     }
 }
 
+/// Icon entry for a single registered component type.
+pub struct MeshlessIcon {
+    /// Billboard texture shown for entities carrying this component.
+    pub texture: Handle<Image>,
+    /// When set, a hidden clickable [`shape::Icosphere`] of this radius is
+    /// spawned alongside the billboard so the entity can be picked.
+    pub clickable_radius: Option<f32>,
+}
+
+/// Maps a component's [`TypeId`] to the billboard icon shown for it in the
+/// viewport. Populated via [`MeshlessIconAppExt::register_meshless_icon`], this
+/// turns meshless visualization into an open subsystem that third-party plugins
+/// can extend without editing this crate.
+#[derive(Resource, Default)]
+pub struct MeshlessIconRegistry {
+    icons: HashMap<TypeId, MeshlessIcon>,
+}
+
+/// Tracks which component types already have a [`visualize_registered_icon`]
+/// system scheduled, so registering the same type twice (or re-registering a
+/// builtin) never runs two identical spawn systems in one frame.
+#[derive(Resource, Default)]
+struct RegisteredIconSystems(HashSet<TypeId>);
+
+/// Radius of the shared `editor_icons.sphere` clickable mesh (the RON default
+/// and the radius every builtin icon registers with), so a request for this
+/// radius can reuse the shared handle instead of allocating a duplicate mesh.
+pub const SHARED_SPHERE_RADIUS: f32 = 0.75;
+
+/// Caches one clickable [`shape::Icosphere`] mesh per distinct radius so
+/// [`visualize_registered_icon`] reuses a handle across every icon of that
+/// size instead of allocating a fresh mesh asset per entity. Radii equal to
+/// [`SHARED_SPHERE_RADIUS`] reuse `editor_icons.sphere` and are never cached
+/// here.
+#[derive(Resource, Default)]
+struct ClickableSphereMeshes(HashMap<u32, Handle<Mesh>>);
+
+/// Registers component types so they show up as billboard icons in the viewport.
+pub trait MeshlessIconAppExt {
+    /// Associates component `T` with `icon` (and an optional clickable sphere
+    /// radius), analogous to [`EditorRegistryExt::editor_registry`]. Every
+    /// [`PrefabMarker`] entity carrying `T` will get a billboard child the next
+    /// time [`visualize_registered_icon`] runs.
+    fn register_meshless_icon<T: Component>(
+        &mut self,
+        icon: Handle<Image>,
+        clickable_radius: Option<f32>,
+    ) -> &mut Self;
+}
+
+impl MeshlessIconAppExt for App {
+    fn register_meshless_icon<T: Component>(
+        &mut self,
+        icon: Handle<Image>,
+        clickable_radius: Option<f32>,
+    ) -> &mut Self {
+        self.init_resource::<MeshlessIconRegistry>();
+        self.init_resource::<RegisteredIconSystems>();
+        self.world
+            .resource_mut::<MeshlessIconRegistry>()
+            .icons
+            .insert(
+                TypeId::of::<T>(),
+                MeshlessIcon {
+                    texture: icon,
+                    clickable_radius,
+                },
+            );
+        // only schedule the spawn system the first time this type is registered
+        if self
+            .world
+            .resource_mut::<RegisteredIconSystems>()
+            .0
+            .insert(TypeId::of::<T>())
+        {
+            self.add_systems(
+                Update,
+                visualize_registered_icon::<T>.in_set(EditorSet::Editor),
+            );
+        }
+        self
+    }
+}
+
+/// Populates the registry with the editor's built-in light and camera icons
+/// once [`EditorIconAssets`] has finished loading.
+pub fn register_builtin_meshless_icons(
+    mut registry: ResMut<MeshlessIconRegistry>,
+    editor_icons: Res<EditorIconAssets>,
+) {
+    let builtins = [
+        (TypeId::of::<DirectionalLight>(), &editor_icons.directional),
+        (TypeId::of::<SpotLight>(), &editor_icons.spot),
+        (TypeId::of::<PointLight>(), &editor_icons.point),
+        (TypeId::of::<Camera>(), &editor_icons.camera),
+    ];
+    for (type_id, texture) in builtins {
+        registry.icons.entry(type_id).or_insert_with(|| MeshlessIcon {
+            texture: texture.clone(),
+            clickable_radius: Some(SHARED_SPHERE_RADIUS),
+        });
+    }
+}
+
+/// Default RON theme shipped with the editor.
+pub const DEFAULT_ICON_THEME: &str = "icons/editor.icons.ron";
+
+/// Tracks the RON theme file currently backing [`EditorIconAssets`] so it can be
+/// hot-reloaded when edited on disk, or swapped for an alternate icon pack at
+/// runtime via [`SwitchIconThemeEvent`].
+#[derive(Resource)]
+pub struct EditorIconTheme {
+    /// Path the collection was loaded from.
+    pub path: String,
+    /// Handle watched for [`AssetEvent::Modified`]/reload.
+    pub handle: Handle<EditorIconAssetCollection>,
+    /// Whether the next [`AssetEvent::LoadedWithDependencies`] for `handle`
+    /// should trigger a rebuild. The loading state already builds the default
+    /// theme during [`EditorState::Loading`], so the initial load is skipped
+    /// to avoid a throwaway rebuild; a runtime theme switch sets this `true`.
+    pub rebuild_on_load: bool,
+}
+
+/// Request to switch the active icon theme to the RON file at the given path.
+#[derive(Event)]
+pub struct SwitchIconThemeEvent(pub String);
+
+/// Set whenever the active theme asset changes and the derived
+/// [`EditorIconAssets`] need to be rebuilt.
+#[derive(Resource, Default)]
+struct IconThemeDirty(bool);
+
+/// Loads the default theme into a handle we own, so its modifications and any
+/// later theme swaps raise [`AssetEvent`]s we can react to.
+pub fn setup_icon_theme(mut commands: Commands, ass: Res<AssetServer>) {
+    commands.insert_resource(EditorIconTheme {
+        path: DEFAULT_ICON_THEME.to_string(),
+        // the asset server dedups by path, so this returns the same collection
+        // handle the loading state already loaded rather than a second copy
+        handle: ass.load(DEFAULT_ICON_THEME),
+        // the default theme was already built during loading; don't rebuild it
+        rebuild_on_load: false,
+    });
+}
+
+/// Points the editor at an alternate RON theme file, reloading the collection so
+/// users can ship multiple icon packs and switch between them at runtime.
+pub fn switch_icon_theme(
+    mut events: EventReader<SwitchIconThemeEvent>,
+    ass: Res<AssetServer>,
+    mut theme: ResMut<EditorIconTheme>,
+) {
+    // only the last request in a frame matters
+    if let Some(SwitchIconThemeEvent(path)) = events.read().last() {
+        theme.path = path.clone();
+        theme.handle = ass.load::<EditorIconAssetCollection>(path);
+        // a switched theme still needs building once it finishes loading
+        theme.rebuild_on_load = true;
+    }
+}
+
+/// Flags the derived icon assets dirty whenever the active theme collection is
+/// modified on disk or a freshly-loaded alternate theme becomes available.
+pub fn watch_icon_theme(
+    mut events: EventReader<AssetEvent<EditorIconAssetCollection>>,
+    mut theme: ResMut<EditorIconTheme>,
+    mut dirty: ResMut<IconThemeDirty>,
+) {
+    for event in events.read() {
+        match event {
+            // an on-disk edit always warrants a rebuild
+            AssetEvent::Modified { id } if *id == theme.handle.id() => {
+                dirty.0 = true;
+            }
+            // only rebuild on load for a switched-in theme; the initial default
+            // load was already built during the loading state
+            AssetEvent::LoadedWithDependencies { id }
+                if *id == theme.handle.id() && theme.rebuild_on_load =>
+            {
+                theme.rebuild_on_load = false;
+                dirty.0 = true;
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Rebuilds [`EditorIconAssets`] from the active theme collection and refreshes
+/// already-spawned billboards in place, rather than despawning them through
+/// [`clean_meshless`]. Runs as an exclusive system because rebuilding a dynamic
+/// asset requires `&mut World`.
+pub fn rebuild_icon_assets(world: &mut World) {
+    if !world.resource::<IconThemeDirty>().0 {
+        return;
+    }
+    world.resource_mut::<IconThemeDirty>().0 = false;
+
+    let theme_handle = world.resource::<EditorIconTheme>().handle.clone();
+    let entries: Vec<(String, EditorIconAssetType)> = {
+        let Some(assets) = world.get_resource::<Assets<EditorIconAssetCollection>>() else {
+            return;
+        };
+        let Some(collection) = assets.get(&theme_handle) else {
+            return;
+        };
+        collection
+            .0
+            .iter()
+            .map(|(k, v)| (k.clone(), v.clone()))
+            .collect()
+    };
+
+    // Re-run the DynamicAsset build path for every key in the theme.
+    let mut built: HashMap<String, UntypedHandle> = HashMap::new();
+    for (key, asset) in &entries {
+        if let Ok(DynamicAssetType::Single(handle)) = asset.build(world) {
+            built.insert(key.clone(), handle);
+        }
+    }
+
+    // Swap the new handles into EditorIconAssets, remembering the old -> new
+    // mapping so existing billboards can be rewired without respawning.
+    let mut image_remap: HashMap<AssetId<Image>, Handle<Image>> = HashMap::new();
+    let mut mesh_remap: HashMap<AssetId<Mesh>, Handle<Mesh>> = HashMap::new();
+    {
+        let mut icons = world.resource_mut::<EditorIconAssets>();
+        remap_image(&mut icons.unknown, built.get("unknown"), &mut image_remap);
+        remap_image(
+            &mut icons.directional,
+            built.get("directional"),
+            &mut image_remap,
+        );
+        remap_image(&mut icons.point, built.get("point"), &mut image_remap);
+        remap_image(&mut icons.spot, built.get("spot"), &mut image_remap);
+        remap_image(&mut icons.camera, built.get("camera"), &mut image_remap);
+        remap_mesh(&mut icons.square, built.get("square"), &mut mesh_remap);
+        remap_mesh(&mut icons.sphere, built.get("sphere"), &mut mesh_remap);
+    }
+
+    // Keep the registry's built-in entries pointing at the new textures.
+    {
+        let mut registry = world.resource_mut::<MeshlessIconRegistry>();
+        for icon in registry.icons.values_mut() {
+            if let Some(new) = image_remap.get(&icon.texture.id()) {
+                icon.texture = new.clone();
+            }
+        }
+    }
+
+    // Finally rewire live billboards in place.
+    let mut textures = world.query::<&mut BillboardTextureHandle>();
+    for mut texture in textures.iter_mut(world) {
+        if let Some(new) = image_remap.get(&texture.0.id()) {
+            texture.0 = new.clone();
+        }
+    }
+    let mut meshes = world.query::<&mut BillboardMeshHandle>();
+    for mut mesh in meshes.iter_mut(world) {
+        if let Some(new) = mesh_remap.get(&mesh.0.id()) {
+            mesh.0 = new.clone();
+        }
+    }
+}
+
+/// Overwrites `field` with the freshly built handle, recording the old asset id
+/// so callers can rewire anything still referencing it.
+fn remap_image(
+    field: &mut Handle<Image>,
+    built: Option<&UntypedHandle>,
+    remap: &mut HashMap<AssetId<Image>, Handle<Image>>,
+) {
+    if let Some(handle) = built {
+        let new = handle.clone().typed::<Image>();
+        remap.insert(field.id(), new.clone());
+        *field = new;
+    }
+}
+
+/// Mesh counterpart of [`remap_image`].
+fn remap_mesh(
+    field: &mut Handle<Mesh>,
+    built: Option<&UntypedHandle>,
+    remap: &mut HashMap<AssetId<Mesh>, Handle<Mesh>>,
+) {
+    if let Some(handle) = built {
+        let new = handle.clone().typed::<Mesh>();
+        remap.insert(field.id(), new.clone());
+        *field = new;
+    }
+}
+
+/// Render layer used exclusively for rendering offscreen thumbnails of
+/// [`MeshlessModel::Object`] entities. It is kept distinct from
+/// [`LAST_RENDER_LAYER`] so the general editor cameras never see the temporary
+/// preview model or its dedicated preview camera.
+pub const PREVIEW_RENDER_LAYER: u8 = LAST_RENDER_LAYER - 1;
+
+/// Side length, in pixels, of a generated thumbnail icon.
+const PREVIEW_ICON_SIZE: u32 = 128;
+
+/// Controls when an [`MeshlessModel::Object`] is drawn as its full mesh versus a
+/// cheap rendered thumbnail billboard.
+#[derive(Resource, Clone, Copy)]
+pub struct ObjectPreviewSettings {
+    /// Distance, in world units, from the active editor camera past which the
+    /// object is shown as a thumbnail billboard instead of its real mesh.
+    pub billboard_distance: f32,
+}
+
+impl Default for ObjectPreviewSettings {
+    fn default() -> Self {
+        Self {
+            billboard_distance: 50.,
+        }
+    }
+}
+
+/// How an icon's on-screen size is derived from the active editor camera.
+#[derive(Clone, Copy, Reflect)]
+pub enum IconSizeMode {
+    /// Keep a roughly constant size on screen: the icon is scaled up with camera
+    /// distance so a light or camera gizmo stays readable no matter how far the
+    /// editor camera pulls back.
+    ConstantScreenSize,
+    /// Keep a fixed size in world space; distant icons naturally shrink away.
+    FixedWorldSize,
+}
+
+/// Controls how billboard icons are scaled relative to the active editor camera
+/// so dense scenes stay legible: up close the icons can be kept from
+/// overwhelming the view, and far away they shrink toward a floor and are culled
+/// entirely past a cutoff instead of lingering as unreadable specks. This is a
+/// size-only treatment — the billboard renderer used here has no per-instance
+/// tint, so there is no texture-alpha fade. Exposed through the reflection-driven
+/// inspector (see [`MeshlessVisualizerPlugin::build`]) so users can dial icon
+/// density per scene, and opted out of per object via
+/// [`CustomMeshless::keep_physical_size`].
+#[derive(Resource, Clone, Copy, Reflect)]
+#[reflect(Resource)]
+pub struct IconDisplaySettings {
+    /// Whether icons keep a constant screen size or a fixed world size.
+    pub size_mode: IconSizeMode,
+    /// Base world-space size of an icon at the reference distance.
+    pub base_size: f32,
+    /// Camera distance at which [`IconSizeMode::ConstantScreenSize`] leaves the
+    /// icon at `base_size`; ignored in [`IconSizeMode::FixedWorldSize`].
+    pub reference_distance: f32,
+    /// Lower clamp on the derived scale, so icons never vanish up close.
+    pub min_scale: f32,
+    /// Upper clamp on the derived scale, so icons never fill the viewport.
+    pub max_scale: f32,
+    /// Distance at which icons begin shrinking toward `min_distant_scale`.
+    pub shrink_start: f32,
+    /// Distance past which icons are culled (hidden) entirely.
+    pub cull_distance: f32,
+    /// Extra scale multiplier an icon reaches at `cull_distance`, in `[0, 1]`:
+    /// icons shrink toward this factor across the `shrink_start..cull_distance`
+    /// band. The final scale is still clamped to `min_scale`, so a shrunk icon
+    /// never vanishes up close.
+    pub min_distant_scale: f32,
+}
+
+impl Default for IconDisplaySettings {
+    fn default() -> Self {
+        Self {
+            size_mode: IconSizeMode::ConstantScreenSize,
+            base_size: 1.,
+            reference_distance: 10.,
+            min_scale: 0.5,
+            max_scale: 4.,
+            shrink_start: 60.,
+            cull_distance: 120.,
+            min_distant_scale: 0.2,
+        }
+    }
+}
+
+/// Identifies the generated thumbnail for a unique (mesh, material) pair so the
+/// same object never gets rendered more than once.
+type PreviewKey = (AssetId<Mesh>, AssetId<StandardMaterial>);
+
+/// Progress of a single offscreen thumbnail render.
+enum PreviewState {
+    /// The preview camera has been spawned and is waiting for its frame.
+    /// `rendered` flips true the first time [`finalize_object_previews`] sees
+    /// the entry, so teardown happens the frame *after* the spawn — once the
+    /// render sub-app has actually drawn into the target image.
+    Rendering {
+        camera: Entity,
+        model: Entity,
+        rendered: bool,
+    },
+    /// The thumbnail has been rendered and the image is ready to be shown.
+    Ready,
+}
+
+struct PreviewEntry {
+    /// Offscreen render target the thumbnail is drawn into.
+    image: Handle<Image>,
+    state: PreviewState,
+}
+
+/// Caches generated thumbnails per (mesh, material) pair so previews are only
+/// rendered once and reused by every impostor billboard that shares them.
+#[derive(Resource, Default)]
+pub struct ObjectPreviewCache {
+    entries: HashMap<PreviewKey, PreviewEntry>,
+}
+
+/// Billboard child that stands in for an [`MeshlessModel::Object`] at a
+/// distance. Stores the (mesh, material) pair it was generated from so the
+/// thumbnail can be regenerated when either handle changes.
+#[derive(Component)]
+pub struct ObjectImpostor {
+    key: PreviewKey,
+}
+
+/// Marks an editor-only icon child (billboard, clickable sphere, impostor, or
+/// object model). Every icon child spawned by the meshless visualizer carries
+/// this; the temporary preview camera and preview model are not children and
+/// are not tagged. The "already visualized?" checks and [`clean_meshless`] key
+/// off this marker instead of fragile billboard-handle presence, and the tagged
+/// gizmos can be stripped before a prefab is saved or duplicated — see [`strip_editor_icons`].
+#[derive(Component, Default)]
+pub struct EditorIcon;
+
+/// The real mesh child of an [`MeshlessModel::Object`], toggled against its
+/// [`ObjectImpostor`] sibling by distance.
+#[derive(Component)]
+pub struct ObjectModel;
+
+/// Temporary camera that renders a single thumbnail frame on the
+/// [`PREVIEW_RENDER_LAYER`].
+#[derive(Component)]
+struct PreviewCamera;
+
+/// Temporary copy of an object's mesh+material, confined to the
+/// [`PREVIEW_RENDER_LAYER`].
+#[derive(Component)]
+struct PreviewModel;
+
 /// Gives the entity some mesh and material to display within the editor
 /// Default is a billboard with a quad mesh and question mark icon
 #[derive(Component, Clone, Default, Reflect)]
@@ -52,6 +553,9 @@ impl Plugin for MeshlessVisualizerPlugin {
 pub struct CustomMeshless {
     /// Visual that will be used to show the entity or object
     pub visual: MeshlessModel,
+    /// Opt out of the distance-based scaling and culling in [`IconDisplaySettings`],
+    /// keeping this object's icon at its authored physical size.
+    pub keep_physical_size: bool,
 }
 
 /// This determines what a custom entity should use as its editor interactable model if it doesn't
@@ -183,91 +687,82 @@ impl DynamicAsset for EditorIconAssetType {
     }
 }
 
-pub fn visualize_meshless(
+/// Spawns a billboard icon child for every [`PrefabMarker`] entity carrying the
+/// registered component `T`. This is the generic replacement for the old
+/// per-light and per-camera loops: the icon texture and optional clickable
+/// sphere come from [`MeshlessIconRegistry`], so new component types only need a
+/// call to [`MeshlessIconAppExt::register_meshless_icon`].
+pub fn visualize_registered_icon<T: Component>(
     mut commands: Commands,
-    lights: Query<
-        (
-            Entity,
-            Option<&Children>,
-            AnyOf<(&DirectionalLight, &SpotLight, &PointLight)>,
-        ),
-        (With<PrefabMarker>, With<Transform>, With<Visibility>),
-    >,
-    cams: Query<
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut sphere_cache: ResMut<ClickableSphereMeshes>,
+    registry: Res<MeshlessIconRegistry>,
+    objects: Query<
         (Entity, Option<&Children>),
         (
-            With<Camera>,
+            With<T>,
             With<PrefabMarker>,
             With<Transform>,
             With<Visibility>,
+            // never draw a gizmo on the editor's own camera, matching the
+            // `Without<EditorCameraMarker>` exclusion of the original cam loop
             Without<EditorCameraMarker>,
         ),
     >,
-    visualized: Query<&BillboardMeshHandle>,
+    visualized: Query<&EditorIcon>,
     editor_icons: Res<EditorIconAssets>,
 ) {
-    for (parent, children, light_type) in &lights {
+    let Some(icon) = registry.icons.get(&TypeId::of::<T>()) else {
+        return;
+    };
+    for (parent, children) in &objects {
         // change is none to doesn't contain
-        // this then covers the case that lights could have children other than these
+        // this then covers the case that entities could have children other than these
         if children.is_none()
             || children.is_some_and(|children| {
                 children.iter().all(|child| visualized.get(*child).is_err())
             })
         {
-            let image = match light_type {
-                (Some(_directional), _, _) => editor_icons.directional.clone(),
-                (_, Some(_spot), _) => editor_icons.spot.clone(),
-                (_, _, Some(_point)) => editor_icons.point.clone(),
-                _ => unreachable!(),
-            };
             // creates a mesh for the icon, as well as a clickable sphere that can be selected to interact with the grandparent, being the actual entity in question
             let child = commands
                 .spawn((
                     BillboardTextureBundle {
                         mesh: bevy_mod_billboard::BillboardMeshHandle(editor_icons.square.clone()),
-                        texture: BillboardTextureHandle(image.clone()),
-                        ..default()
-                    },
-                    RenderLayers::layer(LAST_RENDER_LAYER),
-                ))
-                .with_children(|adult| {
-                    adult.spawn((
-                        MaterialMeshBundle::<StandardMaterial> {
-                            mesh: editor_icons.sphere.clone(),
-                            visibility: Visibility::Hidden,
-                            ..default()
-                        },
-                        SelectParent { parent },
-                    ));
-                })
-                .id();
-            commands.entity(parent).add_child(child);
-        }
-    }
-    for (parent, children) in &cams {
-        if children.is_none()
-            || children.is_some_and(|children| {
-                children.iter().all(|child| visualized.get(*child).is_err())
-            })
-        {
-            let child = commands
-                .spawn((
-                    BillboardTextureBundle {
-                        mesh: bevy_mod_billboard::BillboardMeshHandle(editor_icons.square.clone()),
-                        texture: BillboardTextureHandle(editor_icons.camera.clone()),
+                        texture: BillboardTextureHandle(icon.texture.clone()),
                         ..default()
                     },
+                    EditorIcon,
                     RenderLayers::layer(LAST_RENDER_LAYER),
                 ))
                 .with_children(|adult| {
-                    adult.spawn((
-                        MaterialMeshBundle::<StandardMaterial> {
-                            mesh: editor_icons.sphere.clone(),
-                            visibility: Visibility::Hidden,
-                            ..default()
-                        },
-                        SelectParent { parent },
-                    ));
+                    // honor the registered radius by reusing a clickable sphere
+                    // of that size: the shared handle for the default radius,
+                    // otherwise a mesh built and cached once per distinct radius
+                    if let Some(radius) = icon.clickable_radius {
+                        let sphere = if (radius - SHARED_SPHERE_RADIUS).abs() < f32::EPSILON {
+                            editor_icons.sphere.clone()
+                        } else if let Some(handle) = sphere_cache.0.get(&radius.to_bits()) {
+                            handle.clone()
+                        } else {
+                            let handle = Mesh::try_from(shape::Icosphere {
+                                radius,
+                                ..default()
+                            })
+                            .map(|mesh| meshes.add(mesh))
+                            .unwrap_or_else(|_| editor_icons.sphere.clone());
+                            sphere_cache.0.insert(radius.to_bits(), handle.clone());
+                            handle
+                        };
+                        adult.spawn((
+                            MaterialMeshBundle::<StandardMaterial> {
+                                mesh: sphere,
+                                visibility: Visibility::Hidden,
+                                ..default()
+                            },
+                            EditorIcon,
+                            SelectParent { parent },
+                        ));
+                    }
                 })
                 .id();
             commands.entity(parent).add_child(child);
@@ -282,7 +777,7 @@ pub fn visualize_custom_meshless(
     ass: Res<AssetServer>,
     objects: Query<(Entity, &CustomMeshless, Option<&Children>)>,
     editor_icons: Res<EditorIconAssets>,
-    visualized: Query<&BillboardMeshHandle>,
+    visualized: Query<&EditorIcon>,
 ) {
     for (entity, meshless, children) in objects.iter() {
         if children.is_none()
@@ -306,6 +801,7 @@ pub fn visualize_custom_meshless(
                             ),
                             ..default()
                         },
+                        EditorIcon,
                         RenderLayers::layer(LAST_RENDER_LAYER),
                     ))
                     .with_children(|adult| {
@@ -315,37 +811,495 @@ pub fn visualize_custom_meshless(
                                 visibility: Visibility::Hidden,
                                 ..default()
                             },
+                            EditorIcon,
                             SelectParent { parent: entity },
                         ));
                     })
                     .id(),
-                MeshlessModel::Object { mesh, material } => commands
-                    .spawn((
-                        MaterialMeshBundle {
-                            mesh: mesh.clone().unwrap_or(editor_icons.sphere.clone()),
-                            material: material.clone().unwrap_or(ass.add(StandardMaterial {
-                                unlit: true,
+                MeshlessModel::Object { mesh, material } => {
+                    let mesh = mesh.clone().unwrap_or(editor_icons.sphere.clone());
+                    let material = material.clone().unwrap_or(ass.add(StandardMaterial {
+                        unlit: true,
+                        ..default()
+                    }));
+                    // The real mesh, shown when the camera is close.
+                    let model = commands
+                        .spawn((
+                            MaterialMeshBundle {
+                                mesh: mesh.clone(),
+                                material: material.clone(),
                                 ..default()
-                            })),
-                            ..default()
-                        },
-                        SelectParent { parent: entity },
-                        RenderLayers::layer(LAST_RENDER_LAYER),
-                    ))
-                    .id(),
+                            },
+                            ObjectModel,
+                            EditorIcon,
+                            SelectParent { parent: entity },
+                            RenderLayers::layer(LAST_RENDER_LAYER),
+                        ))
+                        .id();
+                    // A thumbnail billboard standing in for the mesh when far
+                    // away. Its texture starts as the fallback icon and is
+                    // replaced once the offscreen preview has been rendered.
+                    let impostor = commands
+                        .spawn((
+                            BillboardTextureBundle {
+                                mesh: BillboardMeshHandle(editor_icons.square.clone()),
+                                texture: BillboardTextureHandle(editor_icons.unknown.clone()),
+                                visibility: Visibility::Hidden,
+                                ..default()
+                            },
+                            ObjectImpostor {
+                                key: (mesh.id(), material.id()),
+                            },
+                            EditorIcon,
+                            RenderLayers::layer(LAST_RENDER_LAYER),
+                        ))
+                        .with_children(|adult| {
+                            adult.spawn((
+                                MaterialMeshBundle::<StandardMaterial> {
+                                    mesh: editor_icons.sphere.clone(),
+                                    visibility: Visibility::Hidden,
+                                    ..default()
+                                },
+                                EditorIcon,
+                                SelectParent { parent: entity },
+                            ));
+                        })
+                        .id();
+                    commands.entity(entity).push_children(&[model, impostor]);
+                    continue;
+                }
             };
             commands.entity(entity).add_child(child);
         }
     }
 }
 
+/// Keeps each [`ObjectImpostor`]'s cache key in sync with its source
+/// [`CustomMeshless`]. When a user swaps the mesh or material handle of a
+/// [`MeshlessModel::Object`], the impostor's key is updated so
+/// [`request_object_previews`] renders a fresh thumbnail for the new pair
+/// instead of showing the stale one. Handles left at their defaults keep the
+/// key component the impostor was spawned with.
+pub fn refresh_changed_object_impostors(
+    changed: Query<(&CustomMeshless, &Children), Changed<CustomMeshless>>,
+    editor_icons: Res<EditorIconAssets>,
+    mut impostors: Query<&mut ObjectImpostor>,
+) {
+    for (meshless, children) in &changed {
+        let MeshlessModel::Object { mesh, material } = &meshless.visual else {
+            continue;
+        };
+        for child in children.iter() {
+            let Ok(mut impostor) = impostors.get_mut(*child) else {
+                continue;
+            };
+            let mesh_id = mesh
+                .as_ref()
+                .map(|m| m.id())
+                .unwrap_or_else(|| editor_icons.sphere.id());
+            // A `None` material resolves to a per-entity unlit default that has
+            // no stable id, so keep the impostor's current material component.
+            let material_id = material.as_ref().map(|m| m.id()).unwrap_or(impostor.key.1);
+            let key = (mesh_id, material_id);
+            if impostor.key != key {
+                impostor.key = key;
+            }
+        }
+    }
+}
+
+/// Renders an offscreen thumbnail for the next uncached (mesh, material) pair
+/// used by an [`ObjectImpostor`]. A small orthographic camera and a temporary
+/// copy of the model are spawned on the [`PREVIEW_RENDER_LAYER`] only, so the
+/// general editor cameras never observe them. The render target image is cached
+/// in [`ObjectPreviewCache`] so each pair is only rendered once.
+///
+/// Previews are rendered one at a time: the temporary model and camera all
+/// share the single [`PREVIEW_RENDER_LAYER`] at the world origin, so running two
+/// in the same frame would let each camera see the other's geometry and
+/// contaminate the thumbnail. While any preview is still in flight this system
+/// waits, starting the next pair only once [`finalize_object_previews`] has torn
+/// the previous one down.
+pub fn request_object_previews(
+    mut commands: Commands,
+    mut cache: ResMut<ObjectPreviewCache>,
+    mut images: ResMut<Assets<Image>>,
+    impostors: Query<&ObjectImpostor>,
+) {
+    // only one preview may occupy the shared preview layer at a time
+    if cache
+        .entries
+        .values()
+        .any(|entry| matches!(entry.state, PreviewState::Rendering { .. }))
+    {
+        return;
+    }
+
+    let Some(impostor) = impostors
+        .iter()
+        .find(|impostor| !cache.entries.contains_key(&impostor.key))
+    else {
+        return;
+    };
+
+    {
+        let (mesh, material) = impostor.key;
+
+        let size = Extent3d {
+            width: PREVIEW_ICON_SIZE,
+            height: PREVIEW_ICON_SIZE,
+            ..default()
+        };
+        let mut image = Image {
+            texture_descriptor: TextureDescriptor {
+                label: Some("meshless_object_preview"),
+                size,
+                dimension: TextureDimension::D2,
+                format: TextureFormat::Bgra8UnormSrgb,
+                mip_level_count: 1,
+                sample_count: 1,
+                usage: TextureUsages::TEXTURE_BINDING
+                    | TextureUsages::COPY_DST
+                    | TextureUsages::RENDER_ATTACHMENT,
+                view_formats: &[],
+            },
+            ..default()
+        };
+        image.resize(size);
+        let image = images.add(image);
+
+        // A temporary copy of the object, confined to the preview layer.
+        let model = commands
+            .spawn((
+                MaterialMeshBundle::<StandardMaterial> {
+                    mesh: Handle::Weak(mesh),
+                    material: Handle::Weak(material),
+                    ..default()
+                },
+                PreviewModel,
+                RenderLayers::layer(PREVIEW_RENDER_LAYER),
+            ))
+            .id();
+        // An orthographic camera looking at the temporary model, rendering a
+        // single frame into the offscreen image.
+        let camera = commands
+            .spawn((
+                Camera3dBundle {
+                    camera: Camera {
+                        target: RenderTarget::Image(image.clone()),
+                        order: -1,
+                        ..default()
+                    },
+                    projection: Projection::Orthographic(OrthographicProjection {
+                        scaling_mode: bevy::render::camera::ScalingMode::FixedVertical(2.),
+                        ..default()
+                    }),
+                    transform: Transform::from_xyz(0., 0., 5.).looking_at(Vec3::ZERO, Vec3::Y),
+                    ..default()
+                },
+                PreviewCamera,
+                RenderLayers::layer(PREVIEW_RENDER_LAYER),
+            ))
+            .id();
+
+        cache.entries.insert(
+            impostor.key,
+            PreviewEntry {
+                image,
+                state: PreviewState::Rendering {
+                    camera,
+                    model,
+                    rendered: false,
+                },
+            },
+        );
+    }
+}
+
+/// Tears down the temporary preview camera and model one frame after they were
+/// spawned, marks the cached thumbnail ready, and keeps every impostor's
+/// texture pointed at the image cached for its current key. Syncing every
+/// impostor each frame (not just the keys that turned ready this frame) means a
+/// handle change that re-points an impostor at an already-rendered pair picks up
+/// that cached thumbnail instead of keeping the previous pair's image.
+pub fn finalize_object_previews(
+    mut commands: Commands,
+    mut cache: ResMut<ObjectPreviewCache>,
+    mut impostors: Query<(&ObjectImpostor, &mut BillboardTextureHandle)>,
+) {
+    for entry in cache.entries.values_mut() {
+        if let PreviewState::Rendering {
+            camera,
+            model,
+            ref mut rendered,
+        } = entry.state
+        {
+            // Skip the frame the camera/model were spawned in so the render
+            // sub-app gets a chance to draw the thumbnail before we despawn it.
+            if !*rendered {
+                *rendered = true;
+                continue;
+            }
+            commands.entity(camera).despawn_recursive();
+            commands.entity(model).despawn_recursive();
+            entry.state = PreviewState::Ready;
+        }
+    }
+    for (impostor, mut texture) in impostors.iter_mut() {
+        if let Some(entry) = cache.entries.get(&impostor.key) {
+            // only show the thumbnail once it has actually been rendered, and
+            // avoid a redundant change-detection trigger when already correct
+            if matches!(entry.state, PreviewState::Ready) && texture.0.id() != entry.image.id() {
+                texture.0 = entry.image.clone();
+            }
+        }
+    }
+}
+
+/// Swaps each [`MeshlessModel::Object`] between its real mesh and its thumbnail
+/// billboard based on the distance from the active editor camera, using the
+/// threshold in [`ObjectPreviewSettings`].
+pub fn swap_object_impostors(
+    settings: Res<ObjectPreviewSettings>,
+    cameras: Query<&GlobalTransform, (With<EditorCameraMarker>, With<Camera>)>,
+    transforms: Query<&GlobalTransform>,
+    mut models: Query<(&Parent, &mut Visibility), (With<ObjectModel>, Without<ObjectImpostor>)>,
+    mut impostors: Query<(&Parent, &mut Visibility), (With<ObjectImpostor>, Without<ObjectModel>)>,
+) {
+    let Ok(camera) = cameras.get_single() else {
+        return;
+    };
+    let camera_pos = camera.translation();
+
+    for (parent, mut visibility) in models.iter_mut() {
+        let far = transforms
+            .get(parent.get())
+            .map(|t| t.translation().distance(camera_pos) > settings.billboard_distance)
+            .unwrap_or(false);
+        *visibility = if far {
+            Visibility::Hidden
+        } else {
+            Visibility::Inherited
+        };
+    }
+    for (parent, mut visibility) in impostors.iter_mut() {
+        let far = transforms
+            .get(parent.get())
+            .map(|t| t.translation().distance(camera_pos) > settings.billboard_distance)
+            .unwrap_or(false);
+        *visibility = if far {
+            Visibility::Inherited
+        } else {
+            Visibility::Hidden
+        };
+    }
+}
+
+/// Pure scale logic behind [`scale_distant_icons`]: given the display settings
+/// and an icon's distance from the active camera, returns the world-space scale
+/// to apply, or `None` when the icon is past `cull_distance` and should be
+/// hidden. Across `shrink_start..cull_distance` the icon shrinks toward
+/// `min_distant_scale`, but the final scale is floored at `min_scale` so icons
+/// never vanish up close.
+fn icon_display_scale(settings: &IconDisplaySettings, distance: f32) -> Option<f32> {
+    if distance >= settings.cull_distance {
+        return None;
+    }
+    let base = match settings.size_mode {
+        IconSizeMode::ConstantScreenSize => {
+            let reference = settings.reference_distance.max(f32::EPSILON);
+            settings.base_size * distance / reference
+        }
+        IconSizeMode::FixedWorldSize => settings.base_size,
+    };
+    let scale = base.clamp(settings.min_scale, settings.max_scale);
+
+    // shrink toward `min_distant_scale` across the band before the cull distance
+    let shrink = if settings.cull_distance > settings.shrink_start {
+        let t = ((distance - settings.shrink_start)
+            / (settings.cull_distance - settings.shrink_start))
+            .clamp(0., 1.);
+        1. - t * (1. - settings.min_distant_scale)
+    } else {
+        1.
+    };
+
+    Some((scale * shrink).max(settings.min_scale))
+}
+
+/// Scales every billboard icon relative to the active editor camera, using
+/// [`IconDisplaySettings`]: distant icons shrink toward a floor and are culled
+/// past `cull_distance`. Real object meshes and hidden clickable spheres are
+/// left untouched — only the billboards (light/camera gizmos and custom
+/// billboards) are adjusted; object impostors are also skipped, since
+/// [`swap_object_impostors`] drives their visibility. Icons whose parent entity opts out via
+/// [`CustomMeshless::keep_physical_size`] keep their authored size.
+pub fn scale_distant_icons(
+    settings: Res<IconDisplaySettings>,
+    cameras: Query<&GlobalTransform, (With<EditorCameraMarker>, With<Camera>)>,
+    transforms: Query<&GlobalTransform>,
+    custom: Query<&CustomMeshless>,
+    mut icons: Query<
+        (&Parent, &mut Transform, &mut Visibility),
+        (
+            With<EditorIcon>,
+            With<BillboardTextureHandle>,
+            // object impostors toggle their own visibility by distance in
+            // `swap_object_impostors`; leave them alone to avoid fighting it
+            Without<ObjectImpostor>,
+        ),
+    >,
+) {
+    let Ok(camera) = cameras.get_single() else {
+        return;
+    };
+    let camera_pos = camera.translation();
+
+    for (parent, mut transform, mut visibility) in icons.iter_mut() {
+        // objects flagged to keep a physical size are left exactly as authored
+        if custom
+            .get(parent.get())
+            .is_ok_and(|meshless| meshless.keep_physical_size)
+        {
+            continue;
+        }
+        let Ok(parent_transform) = transforms.get(parent.get()) else {
+            continue;
+        };
+        let distance = parent_transform.translation().distance(camera_pos);
+
+        match icon_display_scale(&settings, distance) {
+            // hidden once past the far clamp
+            None => *visibility = Visibility::Hidden,
+            Some(scale) => {
+                *visibility = Visibility::Inherited;
+                transform.scale = Vec3::splat(scale);
+            }
+        }
+    }
+}
+
 pub fn clean_meshless(
     mut commands: Commands,
-    // this covers all entities that are the children of the lights
-    // this can be extended to cover the custom children as well
-    objects: Query<Entity, Or<(With<BillboardTextureHandle>, With<BillboardMeshHandle>)>>,
+    // every editor-only icon child is tagged with `EditorIcon`, so this single
+    // filter covers light, camera, and custom-meshless gizmos alike
+    objects: Query<Entity, With<EditorIcon>>,
 ) {
     for entity in objects.iter() {
         commands.entity(entity).despawn_recursive();
     }
 }
+
+/// Despawns every [`EditorIcon`] descendant beneath `root`, leaving icons under
+/// unrelated entities untouched. Exposed so the save hook ([`strip_editor_icons`])
+/// and the duplicate/clone call site (which lives outside this crate) can both
+/// scope the strip to a single prefab subtree before serializing or copying it.
+pub fn strip_editor_icons_under(
+    commands: &mut Commands,
+    root: Entity,
+    children: &Query<&Children>,
+    icons: &Query<(), With<EditorIcon>>,
+) {
+    let Ok(kids) = children.get(root) else {
+        return;
+    };
+    for &child in kids.iter() {
+        if icons.get(child).is_ok() {
+            // the whole icon subtree (billboard + clickable sphere, or the
+            // impostor + model pair) comes down with it
+            commands.entity(child).despawn_recursive();
+        } else {
+            strip_editor_icons_under(commands, child, children, icons);
+        }
+    }
+}
+
+/// Pre-save hook: when a save is requested, strips the [`EditorIcon`]
+/// descendants out of every prefab root being serialized so exported
+/// `.ron`/`.scn` files never carry editor-only gizmos. Scoped to
+/// [`PrefabMarker`] subtrees rather than despawning every icon in the world, so
+/// gizmos on non-prefab entities (and the live editor view) are left alone; the
+/// stripped icons are respawned next frame by the visualize systems.
+///
+/// Scheduled in [`PreUpdate`] (see [`MeshlessVisualizerPlugin::build`]) so the
+/// deferred despawns flush at the end of that schedule — before the
+/// `Update`-time serialization pass reads the same [`EditorEvent::Save`] —
+/// guaranteeing the exported scene sees the cleaned tree. The duplicate/clone
+/// path lives outside this crate and calls [`strip_editor_icons_under`] on the
+/// clone source itself.
+pub fn strip_editor_icons(
+    mut commands: Commands,
+    mut events: EventReader<EditorEvent>,
+    roots: Query<Entity, With<PrefabMarker>>,
+    children: Query<&Children>,
+    icons: Query<(), With<EditorIcon>>,
+) {
+    if !events
+        .read()
+        .any(|event| matches!(event, EditorEvent::Save(_)))
+    {
+        return;
+    }
+    for root in &roots {
+        strip_editor_icons_under(&mut commands, root, &children, &icons);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn settings() -> IconDisplaySettings {
+        IconDisplaySettings {
+            size_mode: IconSizeMode::FixedWorldSize,
+            base_size: 1.,
+            reference_distance: 10.,
+            min_scale: 0.5,
+            max_scale: 4.,
+            shrink_start: 60.,
+            cull_distance: 120.,
+            min_distant_scale: 0.2,
+        }
+    }
+
+    #[test]
+    fn hidden_past_cull_distance() {
+        assert_eq!(icon_display_scale(&settings(), 120.), None);
+        assert_eq!(icon_display_scale(&settings(), 500.), None);
+    }
+
+    #[test]
+    fn never_smaller_than_min_scale() {
+        let s = settings();
+        // even at the very edge of the shrink band the floor holds
+        for distance in [0., 30., 59., 90., 119.] {
+            let scale = icon_display_scale(&s, distance).unwrap();
+            assert!(scale >= s.min_scale, "scale {scale} dropped below min_scale");
+        }
+    }
+
+    #[test]
+    fn scale_is_non_increasing_across_the_shrink_band() {
+        let s = settings();
+        let mut prev = f32::INFINITY;
+        for distance in [60., 75., 90., 105., 119.] {
+            let scale = icon_display_scale(&s, distance).unwrap();
+            assert!(scale <= prev + f32::EPSILON, "scale grew inside the shrink band");
+            prev = scale;
+        }
+    }
+
+    #[test]
+    fn constant_screen_size_grows_with_distance_until_clamped() {
+        let s = IconDisplaySettings {
+            size_mode: IconSizeMode::ConstantScreenSize,
+            ..settings()
+        };
+        // below the shrink band, larger distance means a larger (or clamped) icon
+        let near = icon_display_scale(&s, 5.).unwrap();
+        let far = icon_display_scale(&s, 40.).unwrap();
+        assert!(far >= near);
+        // clamped to max_scale however far the reference pushes it
+        assert!(icon_display_scale(&s, 59.).unwrap() <= s.max_scale);
+    }
+}